@@ -98,9 +98,9 @@ static int capture_env_t(bear_env_t *env);
 static void release_env_t(bear_env_t *env);
 static char const **string_array_partial_update(char *const envp[], bear_env_t *env);
 static char const **string_array_single_update(char const **in, char const *key, char const *value);
-static void report_call(char const *const argv[]);
-static void write_report(int fd, char const *const argv[]);
-static int write_json_report(int fd, char const *const cmd[], char const *cwd, pid_t pid);
+static void report_call(char const *const argv[], pid_t pid, pid_t ppid);
+static void write_report(int fd, char const *const argv[], pid_t pid, pid_t ppid);
+static int write_json_report(int fd, char const *const cmd[], char const *cwd, pid_t pid, pid_t ppid);
 static int encode_json_string(char const *src, char *dst, size_t dst_size);
 static char const **string_array_from_varargs(char const *arg, va_list *ap);
 static char const **string_array_copy(char const **const in);
@@ -218,7 +218,7 @@ static void mt_safe_on_unload(void) {
 
 #ifdef HAVE_EXECVE
 int execve(const char *path, char *const argv[], char *const envp[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_execve(path, argv, envp);
 }
 #endif
@@ -228,35 +228,35 @@ int execve(const char *path, char *const argv[], char *const envp[]) {
 #error can not implement execv without execve
 #endif
 int execv(const char *path, char *const argv[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_execve(path, argv, environ);
 }
 #endif
 
 #ifdef HAVE_EXECVPE
 int execvpe(const char *file, char *const argv[], char *const envp[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_execvpe(file, argv, envp);
 }
 #endif
 
 #ifdef HAVE_EXECVP
 int execvp(const char *file, char *const argv[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_execvp(file, argv);
 }
 #endif
 
 #ifdef HAVE_EXECVP2
 int execvP(const char *file, const char *search_path, char *const argv[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_execvP(file, search_path, argv);
 }
 #endif
 
 #ifdef HAVE_EXECT
 int exect(const char *path, char *const argv[], char *const envp[]) {
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     return call_exect(path, argv, envp);
 }
 #endif
@@ -271,7 +271,7 @@ int execl(const char *path, const char *arg, ...) {
     char const **argv = string_array_from_varargs(arg, &args);
     va_end(args);
 
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     int const result = call_execve(path, (char *const *)argv, environ);
 
     string_array_release(argv);
@@ -289,7 +289,7 @@ int execlp(const char *file, const char *arg, ...) {
     char const **argv = string_array_from_varargs(arg, &args);
     va_end(args);
 
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     int const result = call_execvp(file, (char *const *)argv);
 
     string_array_release(argv);
@@ -309,7 +309,7 @@ int execle(const char *path, const char *arg, ...) {
     char const **envp = va_arg(args, char const **);
     va_end(args);
 
-    report_call((char const *const *)argv);
+    report_call((char const *const *)argv, getpid(), getppid());
     int const result =
         call_execve(path, (char *const *)argv, (char *const *)envp);
 
@@ -323,8 +323,16 @@ int posix_spawn(pid_t *restrict pid, const char *restrict path,
                 const posix_spawn_file_actions_t *file_actions,
                 const posix_spawnattr_t *restrict attrp,
                 char *const argv[restrict], char *const envp[restrict]) {
-    report_call((char const *const *)argv);
-    return call_posix_spawn(pid, path, file_actions, attrp, argv, envp);
+    // the child is reported with its own pid (and this process as parent)
+    pid_t child = 0;
+    int const result =
+        call_posix_spawn(&child, path, file_actions, attrp, argv, envp);
+    if (pid)
+        *pid = child;
+    // a failed spawn did not run the command
+    if (result == 0)
+        report_call((char const *const *)argv, child, getpid());
+    return result;
 }
 #endif
 
@@ -333,8 +341,16 @@ int posix_spawnp(pid_t *restrict pid, const char *restrict file,
                  const posix_spawn_file_actions_t *file_actions,
                  const posix_spawnattr_t *restrict attrp,
                  char *const argv[restrict], char *const envp[restrict]) {
-    report_call((char const *const *)argv);
-    return call_posix_spawnp(pid, file, file_actions, attrp, argv, envp);
+    // the child is reported with its own pid (and this process as parent)
+    pid_t child = 0;
+    int const result =
+        call_posix_spawnp(&child, file, file_actions, attrp, argv, envp);
+    if (pid)
+        *pid = child;
+    // a failed spawn did not run the command
+    if (result == 0)
+        report_call((char const *const *)argv, child, getpid());
+    return result;
 }
 #endif
 
@@ -462,7 +478,7 @@ static int call_posix_spawnp(pid_t *restrict pid, const char *restrict file,
 
 /* this method is to write log about the process creation. */
 
-static void report_call(char const *const argv[]) {
+static void report_call(char const *const argv[], pid_t pid, pid_t ppid) {
     if (!initialized)
         return;
     // Create report file name
@@ -476,13 +492,13 @@ static void report_call(char const *const argv[]) {
     if (-1 == fd)
         ERROR_AND_EXIT("mkstemp");
     // Write report file
-    write_report(fd, argv);
+    write_report(fd, argv, pid, ppid);
     // Close report file
     if (close(fd))
         ERROR_AND_EXIT("close");
 }
 
-static void write_report(int fd, char const *const argv[]) {
+static void write_report(int fd, char const *const argv[], pid_t pid, pid_t ppid) {
     const locale_t saved_locale = uselocale(utf_locale);
     if ((locale_t)0 == saved_locale)
         ERROR_AND_EXIT("uselocale");
@@ -490,7 +506,7 @@ static void write_report(int fd, char const *const argv[]) {
     const char *cwd = getcwd(NULL, 0);
     if (0 == cwd)
         ERROR_AND_EXIT("getcwd");
-    if (write_json_report(fd, argv, cwd, getpid()))
+    if (write_json_report(fd, argv, cwd, pid, ppid))
         ERROR_AND_EXIT("writing json problem");
     free((void *)cwd);
 
@@ -499,8 +515,8 @@ static void write_report(int fd, char const *const argv[]) {
         ERROR_AND_EXIT("uselocale");
 }
 
-static int write_json_report(int fd, char const *const cmd[], char const *const cwd, pid_t pid) {
-    if (0 > dprintf(fd, "{ \"pid\": %d, \"cmd\": [", pid))
+static int write_json_report(int fd, char const *const cmd[], char const *const cwd, pid_t pid, pid_t ppid) {
+    if (0 > dprintf(fd, "{ \"pid\": %d, \"ppid\": %d, \"cmd\": [", pid, ppid))
         return -1;
 
     for (char const *const *it = cmd; (it) && (*it); ++it) {
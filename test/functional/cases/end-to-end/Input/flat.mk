@@ -0,0 +1,21 @@
+# non-recursive build of the library and the application
+
+CXXFLAGS = -Ilib
+
+some_app: bin/far.o bin/boo.o libsome_lib.a
+	$(CXX) -o $@ bin/far.o bin/boo.o -L. -lsome_lib
+
+libsome_lib.a: lib/foo.o lib/bar.o
+	$(AR) rcs $@ lib/foo.o lib/bar.o
+
+bin/far.o: bin/far.cxx
+	$(CXX) $(CXXFLAGS) -c -o $@ bin/far.cxx
+
+bin/boo.o: bin/boo.c++
+	$(CXX) $(CXXFLAGS) -c -o $@ bin/boo.c++
+
+lib/foo.o: lib/foo.cpp
+	$(CXX) -c -o $@ lib/foo.cpp
+
+lib/bar.o: lib/bar.cc
+	$(CXX) -c -o $@ lib/bar.cc
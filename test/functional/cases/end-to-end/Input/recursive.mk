@@ -0,0 +1,5 @@
+# recursive build of the library and the application
+
+all:
+	$(MAKE) -C lib
+	$(MAKE) -C bin